@@ -4,6 +4,7 @@
 
 use clap::{Args, Parser};
 use runtime::{OutputFormat, RuntimeArgs};
+use std::time::Duration;
 
 #[derive(Parser)]
 #[command(
@@ -18,6 +19,14 @@ struct Cli {
     /// Show container uptime indicators
     #[arg(short, long, default_value_t = false)]
     container: bool,
+
+    /// Continuously refresh the dashboard instead of printing once
+    #[arg(short, long, default_value_t = false)]
+    watch: bool,
+
+    /// Refresh interval in seconds when `--watch` is set
+    #[arg(long, default_value_t = 2)]
+    interval: u64,
 }
 
 #[derive(Args)]
@@ -42,6 +51,10 @@ struct Outputs {
     /// Show standard uptime format (like original uptime)
     #[arg(long)]
     standard: bool,
+
+    /// Show machine-parseable JSON output, with no colors or icons
+    #[arg(long)]
+    json: bool,
 }
 /// Parse command line arguments
 ///
@@ -56,16 +69,20 @@ pub fn parse_args() -> RuntimeArgs {
         output.raw,
         output.since,
         output.interactive,
+        output.json,
     ) {
-        (true, _, _, _, _) => OutputFormat::Standard,
-        (_, true, _, _, _) => OutputFormat::Pretty,
-        (_, _, true, _, _) => OutputFormat::Raw,
-        (_, _, _, true, _) => OutputFormat::Since,
+        (true, _, _, _, _, _) => OutputFormat::Standard,
+        (_, true, _, _, _, _) => OutputFormat::Pretty,
+        (_, _, true, _, _, _) => OutputFormat::Raw,
+        (_, _, _, true, _, _) => OutputFormat::Since,
+        (_, _, _, _, _, true) => OutputFormat::Json,
         _ => OutputFormat::Interactive,
     };
 
     RuntimeArgs {
         format,
         show_container: cli.container,
+        watch: cli.watch,
+        interval: Duration::from_secs(cli.interval),
     }
 }
@@ -1,14 +1,66 @@
+//! Core `Runtime` dashboard and metrics types, consumed as the `runtime`
+//! library crate by an out-of-tree binary
+//!
+//! `cli.rs` in this source tree is that binary's argument-parsing module
+//! (note its `use runtime::{OutputFormat, RuntimeArgs};`), not a module of
+//! this crate — the binary is expected to call [`Runtime::new`] with the
+//! parsed [`RuntimeArgs`] and, when `args.watch` is set, hand `args.interval`
+//! to [`Runtime::watch`] instead of printing once.
+
 use colored::*;
+use std::collections::VecDeque;
 use std::fmt::Display;
+use std::io::{self, Write};
+use std::time::Duration;
 
 pub mod system_metrics;
 use system_metrics::SystemMetrics;
 
+/// Number of samples kept in the rolling load/CPU history ring buffers
+const HISTORY_CAPACITY: usize = 32;
+
+/// Block glyphs used to render sparklines, from lowest to highest
+const SPARK_GLYPHS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+/// Push a sample into a fixed-size ring buffer, evicting the oldest entry
+fn push_sample(history: &mut VecDeque<f64>, value: f64) {
+    if history.len() == HISTORY_CAPACITY {
+        history.pop_front();
+    }
+    history.push_back(value);
+}
+
+/// Render a ring buffer of samples as an inline sparkline
+fn sparkline(history: &VecDeque<f64>) -> String {
+    if history.is_empty() {
+        return String::new();
+    }
+
+    let min = history.iter().cloned().fold(f64::INFINITY, f64::min);
+    let max = history.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+
+    history
+        .iter()
+        .map(|&value| {
+            let index = if (max - min).abs() < f64::EPSILON {
+                0
+            } else {
+                (((value - min) / (max - min)) * 7.0).clamp(0.0, 7.0).round() as usize
+            };
+            SPARK_GLYPHS[index]
+        })
+        .collect()
+}
+
 /// Runtime structure that holds system metrics and formatting options
 #[derive(Debug, Clone)]
 pub struct Runtime {
     args: RuntimeArgs,
     system: SystemMetrics,
+    /// Rolling history of 1-minute load averages, most recent last
+    load_history: VecDeque<f64>,
+    /// Rolling history of overall CPU usage percentages, most recent last
+    cpu_history: VecDeque<f64>,
 }
 
 impl Default for Runtime {
@@ -28,19 +80,55 @@ impl PartialEq for Runtime {
 impl Runtime {
     /// Creates a new Runtime instance
     pub fn new(args: RuntimeArgs) -> Runtime {
-        Self {
+        let mut runtime = Self {
             args,
             system: SystemMetrics::new().unwrap_or_default(),
-        }
+            load_history: VecDeque::with_capacity(HISTORY_CAPACITY),
+            cpu_history: VecDeque::with_capacity(HISTORY_CAPACITY),
+        };
+        runtime.record_history();
+        runtime
     }
 
-    /// Refreshes system metrics
+    /// Refreshes system metrics and records a new history sample
     pub fn refresh(&mut self) {
         if let Ok(()) = self.system.refresh() {
-            // Metrics refreshed successfully
+            self.record_history();
         }
     }
 
+    /// Append the current load average and CPU usage to the rolling history
+    fn record_history(&mut self) {
+        let (load1, _, _) = self.system.load_averages();
+        push_sample(&mut self.load_history, load1);
+        if let Some(cpu_usage) = self.system.cpu_usage() {
+            push_sample(&mut self.cpu_history, cpu_usage);
+        }
+    }
+
+    /// Render the dashboard on a fixed interval, clearing the screen between frames
+    ///
+    /// Intended to back the `--watch`/`--interval` flags; loops until the
+    /// process is interrupted.
+    pub fn watch(&mut self, interval: Duration) -> io::Result<()> {
+        loop {
+            print!("\x1B[2J\x1B[H{}", self);
+            io::stdout().flush()?;
+            std::thread::sleep(interval);
+            self.refresh();
+        }
+    }
+
+    /// Load-average sparkline over the rolling history window
+    fn format_load_sparkline(&self) -> String {
+        sparkline(&self.load_history).bright_blue().bold().to_string()
+    }
+
+    /// CPU-usage sparkline over the rolling history window
+    fn format_cpu_sparkline(&self) -> String {
+        sparkline(&self.cpu_history).bright_red().bold().to_string()
+    }
+
     /// Get system uptime as a nicely formatted string with colors
     fn format_uptime_fancy(&self) -> String {
         let uptime_secs = self.system.uptime_seconds();
@@ -91,57 +179,107 @@ impl Runtime {
         )
     }
 
-    /// Create a clean table layout without nerd fonts
-    fn create_table(&self) -> String {
-        let uptime_fancy = self.format_uptime_fancy();
-        let load_fancy = self.format_load_fancy();
-        let user_count = self.system.user_count();
-        let boot_time = self.system.boot_time();
-        let boot_datetime = chrono::DateTime::from_timestamp(boot_time as i64, 0)
-            .unwrap_or_default()
-            .with_timezone(&chrono::Local);
-        let current_time = chrono::Local::now();
-        let icon = "".bright_cyan().bold();
-        let time_icon = "".bright_yellow().bold();
-        let uptime_icon = "".bright_green().bold();
-        let boot_icon = "".bright_magenta().bold();
-        let user_icon = "".bright_blue().bold();
-        let load_icon = "".bright_red().bold();
-        let border = "─".repeat(40).bright_blue().bold();
-        let mode_str = if self.system.in_container() {
-            "󰆧 Container".bright_cyan().bold()
+    /// Get CPU usage with color coding based on utilization
+    fn format_cpu_fancy(&self) -> String {
+        match self.system.cpu_usage() {
+            Some(usage) => {
+                if usage < 50.0 {
+                    format!("{:.1}%", usage).bright_green().bold().to_string()
+                } else if usage < 80.0 {
+                    format!("{:.1}%", usage).bright_yellow().bold().to_string()
+                } else {
+                    format!("{:.1}%", usage).bright_red().bold().to_string()
+                }
+            }
+            None => "N/A".dimmed().to_string(),
+        }
+    }
+
+    /// Get idle-time percentage with color coding; unlike CPU usage, high
+    /// idle time is good, so the thresholds run in the opposite direction
+    fn format_idle_fancy(&self) -> String {
+        match self.system.idle_percentage() {
+            Some(idle_pct) if idle_pct > 50.0 => {
+                format!("{:.1}%", idle_pct).bright_green().bold().to_string()
+            }
+            Some(idle_pct) if idle_pct > 20.0 => {
+                format!("{:.1}%", idle_pct).bright_yellow().bold().to_string()
+            }
+            Some(idle_pct) => format!("{:.1}%", idle_pct).bright_red().bold().to_string(),
+            None => "N/A".dimmed().to_string(),
+        }
+    }
+
+    /// Render a ratio (0.0..=1.0) as a color-coded usage bar
+    fn format_usage_bar(ratio: f64) -> String {
+        const SEGMENTS: usize = 10;
+        let pct = (ratio * 100.0).clamp(0.0, 100.0);
+        let filled = ((pct / 100.0) * SEGMENTS as f64).round() as usize;
+        let bar = format!(
+            "{}{}",
+            "█".repeat(filled),
+            "░".repeat(SEGMENTS - filled)
+        );
+
+        let colored_bar = if pct < 60.0 {
+            bar.bright_green().bold()
+        } else if pct < 85.0 {
+            bar.bright_yellow().bold()
         } else {
-            " Native".bright_green().bold()
+            bar.bright_red().bold()
         };
+
+        format!("{} {:.1}%", colored_bar, pct)
+    }
+
+    /// Get memory usage as a color-coded usage bar
+    fn format_memory_fancy(&self) -> String {
+        Self::format_usage_bar(self.system.memory_used_ratio())
+    }
+
+    /// Get swap usage as a color-coded usage bar
+    fn format_swap_fancy(&self) -> String {
+        Self::format_usage_bar(self.system.swap_used_ratio())
+    }
+
+    /// Render metrics as a single-line, machine-parseable JSON object
+    ///
+    /// Unlike the other formats, this never includes color escapes or nerd
+    /// font icons, regardless of whether stdout is a TTY.
+    fn format_json(&self) -> String {
+        let (load1, load5, load15) = self.system.load_averages();
+        let cpu_usage = match self.system.cpu_usage() {
+            Some(usage) => format!("{:.2}", usage),
+            None => "null".to_string(),
+        };
+        let idle_percent = match self.system.idle_percentage() {
+            Some(idle_pct) => format!("{:.2}", idle_pct),
+            None => "null".to_string(),
+        };
+        let per_cpu_usage = self
+            .system
+            .per_cpu_usage()
+            .iter()
+            .map(|usage| format!("{:.2}", usage))
+            .collect::<Vec<_>>()
+            .join(",");
+
         format!(
-            "\n{} {} SYSTEM UPTIME {}\n{} {} {}\n{} {} {}\n{} {} {}\n{} {} {}\n{} {} {}\n{}\n",
-            border,
-            icon,
-            border,
-            time_icon,
-            "Time:",
-            current_time
-                .format("%H:%M:%S %Z")
-                .to_string()
-                .bright_white()
-                .bold(),
-            uptime_icon,
-            "Uptime:",
-            uptime_fancy,
-            boot_icon,
-            "Boot:",
-            boot_datetime
-                .format("%Y-%m-%d %H:%M:%S")
-                .to_string()
-                .bright_white()
-                .bold(),
-            user_icon,
-            "Users:",
-            user_count.to_string().bright_cyan().bold(),
-            load_icon,
-            "Load:",
-            load_fancy,
-            mode_str
+            "{{\"timestamp\":{},\"uptime_seconds\":{:.2},\"idle_seconds\":{:.2},\"idle_percent\":{},\"boot_time\":{},\"users\":{},\"load\":[{:.2},{:.2},{:.2}],\"in_container\":{},\"cpu_usage_percent\":{},\"per_cpu_usage_percent\":[{}],\"memory_used_ratio\":{:.4},\"swap_used_ratio\":{:.4}}}",
+            chrono::Utc::now().timestamp(),
+            self.system.uptime_seconds(),
+            self.system.idle_time(),
+            idle_percent,
+            self.system.boot_time(),
+            self.system.user_count(),
+            load1,
+            load5,
+            load15,
+            self.system.in_container(),
+            cpu_usage,
+            per_cpu_usage,
+            self.system.memory_used_ratio(),
+            self.system.swap_used_ratio()
         )
     }
 }
@@ -149,6 +287,7 @@ impl Runtime {
 impl Display for Runtime {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self.args.format {
+            OutputFormat::Json => write!(f, "{}", self.format_json()),
             OutputFormat::Raw => {
                 // Match uptime's --raw: <current time> <time since boot> <logged in users> <load averages>
                 let icon = "".bright_cyan().bold();
@@ -156,16 +295,24 @@ impl Display for Runtime {
                 let uptime_secs = self.system.uptime_seconds();
                 let user_count = self.system.user_count();
                 let (load1, load5, load15) = self.system.load_averages();
+                // followed by idle seconds and idle percentage ("N/A" when unknown)
+                let idle_percent = self
+                    .system
+                    .idle_percentage()
+                    .map(|idle_pct| format!("{:.2}", idle_pct))
+                    .unwrap_or_else(|| "N/A".to_string());
                 write!(
                     f,
-                    "{} {} {} {} {:.2} {:.2} {:.2}",
+                    "{} {} {} {} {:.2} {:.2} {:.2} {:.2} {}",
                     icon,
                     current_time.to_string().bright_white().bold(),
                     uptime_secs.to_string().bright_yellow().bold(),
                     user_count.to_string().bright_green().bold(),
                     load1,
                     load5,
-                    load15
+                    load15,
+                    self.system.idle_time(),
+                    idle_percent
                 )
             }
             OutputFormat::Pretty => {
@@ -245,6 +392,10 @@ impl Display for Runtime {
                 let user_str = if user_count == 1 { "user" } else { "users" };
                 let (load1, load5, load15) = self.system.load_averages();
                 let load_str = format!("{:.2}, {:.2}, {:.2}", load1, load5, load15);
+                let cpu_str = self.format_cpu_fancy();
+                let mem_str = self.format_memory_fancy();
+                let swap_str = self.format_swap_fancy();
+                let idle_str = self.format_idle_fancy();
                 let container_icon = if self.args.show_container && self.system.in_container() {
                     "󰆧 ".bright_magenta().bold().to_string()
                 } else {
@@ -267,7 +418,7 @@ impl Display for Runtime {
                 };
                 write!(
                     f,
-                    "{} {} {}up {}{},  {} {},  load average: {}",
+                    "{} {} {}up {}{},  {} {},  load average: {},  cpu: {},  mem: {},  swap: {},  idle: {}",
                     icon,
                     container_icon,
                     time_str,
@@ -279,7 +430,11 @@ impl Display for Runtime {
                     },
                     user_count.to_string().bright_green().bold(),
                     user_str.bright_green().bold(),
-                    load_str
+                    load_str,
+                    cpu_str,
+                    mem_str,
+                    swap_str,
+                    idle_str
                 )
             }
             OutputFormat::Interactive => {
@@ -290,9 +445,14 @@ impl Display for Runtime {
                 let boot_icon = "".bright_magenta().bold();
                 let user_icon = "".bright_blue().bold();
                 let load_icon = "".bright_red().bold();
+                let cpu_icon = "".bright_red().bold();
+                let mem_icon = "".bright_magenta().bold();
+                let swap_icon = "".bright_magenta().bold();
+                let idle_icon = "".bright_green().bold();
                 let border = "─".repeat(40).bright_blue().bold();
                 let uptime_fancy = self.format_uptime_fancy();
                 let load_fancy = self.format_load_fancy();
+                let cpu_fancy = self.format_cpu_fancy();
                 let user_count = self.system.user_count();
                 let boot_time = self.system.boot_time();
                 let boot_datetime = chrono::DateTime::from_timestamp(boot_time as i64, 0)
@@ -301,7 +461,7 @@ impl Display for Runtime {
                 let current_time = chrono::Local::now();
                 write!(
                     f,
-                    "\n{} {} SYSTEM UPTIME {}\n{} {} {}\n{} {} {}\n{} {} {}\n{} {} {}\n{} {} {} {}\n",
+                    "\n{} {} SYSTEM UPTIME {}\n{} {} {}\n{} {} {}\n{} {} {}\n{} {} {}\n{} {} {}\n{} {} {}\n{} {} {}\n{} {} {}\n{} {} {}\n{} {} {}\n{} {} {}\n{}\n",
                     border,
                     icon,
                     border,
@@ -320,7 +480,25 @@ impl Display for Runtime {
                     load_icon,
                     "Load:",
                     load_fancy,
-                    if self.system.in_container() { "󰆧 Container".bright_cyan().bold() } else { " Native".bright_green().bold() }
+                    load_icon,
+                    "Trend:",
+                    self.format_load_sparkline(),
+                    cpu_icon,
+                    "CPU:",
+                    cpu_fancy,
+                    cpu_icon,
+                    "Trend:",
+                    self.format_cpu_sparkline(),
+                    mem_icon,
+                    "Mem:",
+                    self.format_memory_fancy(),
+                    swap_icon,
+                    "Swap:",
+                    self.format_swap_fancy(),
+                    idle_icon,
+                    "Idle:",
+                    self.format_idle_fancy(),
+                    if self.system.in_container() { "󰆧 Container".bright_cyan().bold().to_string() } else { " Native".bright_green().bold().to_string() }
                 )
             }
         }
@@ -340,6 +518,8 @@ pub enum OutputFormat {
     Since,
     /// Interactive colorful table format
     Interactive,
+    /// Machine-parseable JSON format, with no colors or icons
+    Json,
 }
 
 impl Default for OutputFormat {
@@ -353,6 +533,10 @@ impl Default for OutputFormat {
 pub struct RuntimeArgs {
     pub format: OutputFormat,
     pub show_container: bool,
+    /// Continuously re-render the dashboard instead of printing once
+    pub watch: bool,
+    /// Delay between frames when `watch` is enabled
+    pub interval: Duration,
 }
 
 impl Default for RuntimeArgs {
@@ -360,6 +544,58 @@ impl Default for RuntimeArgs {
         Self {
             format: OutputFormat::Interactive,
             show_container: false,
+            watch: false,
+            interval: Duration::from_secs(2),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sparkline_maps_ascending_samples_across_the_full_glyph_range() {
+        let history: VecDeque<f64> = (0..8).map(|i| i as f64).collect();
+
+        let rendered = sparkline(&history);
+
+        assert_eq!(rendered, SPARK_GLYPHS.iter().collect::<String>());
+    }
+
+    #[test]
+    fn sparkline_uses_the_lowest_glyph_when_all_samples_are_equal() {
+        // max == min is a degenerate case that would otherwise divide by zero.
+        let history: VecDeque<f64> = [5.0, 5.0, 5.0].into_iter().collect();
+
+        let rendered = sparkline(&history);
+
+        assert_eq!(rendered, SPARK_GLYPHS[0].to_string().repeat(3));
+    }
+
+    #[test]
+    fn sparkline_of_empty_history_is_empty() {
+        let history: VecDeque<f64> = VecDeque::new();
+
+        assert_eq!(sparkline(&history), "");
+    }
+
+    #[test]
+    fn format_json_renders_unset_metrics_as_bare_null_not_a_quoted_string() {
+        // A freshly constructed Runtime has taken only one CPU sample, so
+        // cpu_usage() is still None; the hand-rolled template must emit a
+        // bare `null` there rather than `"null"`.
+        let runtime = Runtime::new(RuntimeArgs::default());
+
+        let json = runtime.format_json();
+
+        assert!(json.starts_with('{') && json.ends_with('}'));
+        assert!(json.contains("\"cpu_usage_percent\":null"));
+        assert!(!json.contains("\"cpu_usage_percent\":\"null\""));
+        assert!(json.contains("\"uptime_seconds\":"));
+        assert!(json.contains("\"boot_time\":"));
+        assert!(json.contains("\"users\":"));
+        assert!(json.contains("\"memory_used_ratio\":"));
+        assert!(json.contains("\"swap_used_ratio\":"));
+    }
+}
@@ -0,0 +1,76 @@
+//! Linux metrics backend reading directly from the /proc filesystem and utmpx
+
+use super::source::MetricsSource;
+use std::collections::HashSet;
+use std::fs;
+use std::io;
+use std::time::{SystemTime, UNIX_EPOCH};
+use utmpx::{close_database, read_next_entry, sys::UtType};
+
+/// Reads uptime and load from Linux's /proc filesystem, and users from utmpx
+#[derive(Debug, Default, Clone, Copy)]
+pub struct LinuxSource;
+
+impl MetricsSource for LinuxSource {
+    /// Read uptime and idle time from /proc/uptime
+    fn read_uptime(&self) -> io::Result<(f64, f64)> {
+        let content = fs::read_to_string("/proc/uptime")?;
+        let parts: Vec<&str> = content.trim().split_whitespace().collect();
+
+        if parts.len() >= 2 {
+            let uptime = parts[0].parse().unwrap_or(0.0);
+            let idle = parts[1].parse().unwrap_or(0.0);
+            Ok((uptime, idle))
+        } else {
+            Ok((0.0, 0.0))
+        }
+    }
+
+    /// Read load averages from /proc/loadavg
+    fn read_load_averages(&self) -> io::Result<(f64, f64, f64)> {
+        let content = fs::read_to_string("/proc/loadavg")?;
+        let parts: Vec<&str> = content.trim().split_whitespace().collect();
+
+        if parts.len() >= 3 {
+            let load1 = parts[0].parse().unwrap_or(0.0);
+            let load5 = parts[1].parse().unwrap_or(0.0);
+            let load15 = parts[2].parse().unwrap_or(0.0);
+            Ok((load1, load5, load15))
+        } else {
+            Ok((0.0, 0.0, 0.0))
+        }
+    }
+
+    /// Count unique users from the utmp database
+    fn read_user_count(&self) -> usize {
+        let mut unique_users: HashSet<Vec<u8>> = HashSet::new();
+
+        while let Ok(utmp) = read_next_entry() {
+            // UtType::USER_PROCESS is a logged in user
+            if matches!(utmp.ut_type, UtType::USER_PROCESS) {
+                // Take ut_user up to the first null byte
+                let user_bytes: Vec<u8> = utmp
+                    .ut_user
+                    .iter()
+                    .take_while(|&&c| c != 0)
+                    .map(|&c| c as u8)
+                    .collect();
+
+                unique_users.insert(user_bytes);
+            }
+        }
+
+        close_database();
+        unique_users.len()
+    }
+
+    /// Calculate boot time from current time minus uptime
+    fn read_boot_time(&self, uptime_seconds: f64) -> io::Result<u64> {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+
+        Ok(now.saturating_sub(uptime_seconds as u64))
+    }
+}
@@ -0,0 +1,101 @@
+//! macOS metrics backend using `sysctl` and `getloadavg`
+//!
+//! macOS has no `/proc`, so uptime is derived from `kern.boottime` and load
+//! averages come from the C library directly. The `utmpx` crate only builds
+//! its `UtType`/`Utmpx` definitions for glibc/musl Linux, so user accounting
+//! here reads the Darwin utmpx database directly via `getutxent(3)`.
+
+use super::source::MetricsSource;
+use std::collections::HashSet;
+use std::io;
+use std::mem;
+use std::ptr;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Reads uptime, load, and user metrics via macOS `sysctl` and `getloadavg`
+#[derive(Debug, Default, Clone, Copy)]
+pub struct MacOsSource;
+
+impl MacOsSource {
+    /// Read `kern.boottime` via `sysctlbyname`, returning a UNIX timestamp
+    fn boot_timestamp(&self) -> io::Result<u64> {
+        let mut boottime: libc::timeval = unsafe { mem::zeroed() };
+        let mut size = mem::size_of::<libc::timeval>();
+        let name = b"kern.boottime\0";
+
+        let ret = unsafe {
+            libc::sysctlbyname(
+                name.as_ptr() as *const libc::c_char,
+                &mut boottime as *mut _ as *mut libc::c_void,
+                &mut size,
+                ptr::null_mut(),
+                0,
+            )
+        };
+
+        if ret != 0 {
+            return Err(io::Error::last_os_error());
+        }
+
+        Ok(boottime.tv_sec as u64)
+    }
+}
+
+impl MetricsSource for MacOsSource {
+    /// Derive uptime from `kern.boottime`; macOS has no idle-time counter
+    /// equivalent to the second field of Linux's /proc/uptime
+    fn read_uptime(&self) -> io::Result<(f64, f64)> {
+        let boot_time = self.boot_timestamp()?;
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+
+        Ok((now.saturating_sub(boot_time) as f64, 0.0))
+    }
+
+    fn read_load_averages(&self) -> io::Result<(f64, f64, f64)> {
+        let mut loads = [0.0f64; 3];
+        let filled = unsafe { libc::getloadavg(loads.as_mut_ptr(), 3) };
+
+        if filled != 3 {
+            return Err(io::Error::new(io::ErrorKind::Other, "getloadavg failed"));
+        }
+
+        Ok((loads[0], loads[1], loads[2]))
+    }
+
+    /// Count unique users by walking the Darwin utmpx database directly,
+    /// since the `utmpx` crate has no macOS-compatible `UtType`/`Utmpx` types
+    fn read_user_count(&self) -> usize {
+        let mut unique_users: HashSet<Vec<u8>> = HashSet::new();
+
+        unsafe {
+            libc::setutxent();
+            loop {
+                let entry = libc::getutxent();
+                if entry.is_null() {
+                    break;
+                }
+
+                if (*entry).ut_type == libc::USER_PROCESS {
+                    let user_bytes: Vec<u8> = (*entry)
+                        .ut_user
+                        .iter()
+                        .take_while(|&&c| c != 0)
+                        .map(|&c| c as u8)
+                        .collect();
+
+                    unique_users.insert(user_bytes);
+                }
+            }
+            libc::endutxent();
+        }
+
+        unique_users.len()
+    }
+
+    fn read_boot_time(&self, _uptime_seconds: f64) -> io::Result<u64> {
+        self.boot_timestamp()
+    }
+}
@@ -0,0 +1,566 @@
+//! Low-level, cross-platform system metrics collection
+//!
+//! This module provides precise system metrics by delegating the
+//! platform-specific details (proc filesystem, sysctl, utmp formats, ...) to
+//! a [`MetricsSource`] implementation selected at compile time, matching the
+//! behavior of the standard uptime command on each supported OS.
+
+mod source;
+
+#[cfg(target_os = "linux")]
+mod linux;
+#[cfg(target_os = "macos")]
+mod macos;
+#[cfg(target_os = "openbsd")]
+mod openbsd;
+
+#[cfg(target_os = "linux")]
+use linux::LinuxSource as PlatformSource;
+#[cfg(target_os = "macos")]
+use macos::MacOsSource as PlatformSource;
+#[cfg(target_os = "openbsd")]
+use openbsd::OpenBsdSource as PlatformSource;
+
+use source::MetricsSource;
+use std::io;
+#[cfg(target_os = "linux")]
+use std::{
+    fs,
+    io::{BufRead, BufReader},
+};
+
+/// Cumulative CPU time counters (in USER_HZ ticks) as reported by /proc/stat
+#[cfg(target_os = "linux")]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+struct CpuTimes {
+    user: u64,
+    nice: u64,
+    system: u64,
+    idle: u64,
+    iowait: u64,
+    irq: u64,
+    softirq: u64,
+    steal: u64,
+    guest: u64,
+    guest_nice: u64,
+}
+
+#[cfg(target_os = "linux")]
+impl CpuTimes {
+    /// Parse the counter fields following a `cpu`/`cpuN` label
+    fn parse(fields: &[&str]) -> Option<Self> {
+        if fields.len() < 4 {
+            return None;
+        }
+        let field = |i: usize| fields.get(i).and_then(|s| s.parse().ok()).unwrap_or(0);
+        Some(Self {
+            user: field(0),
+            nice: field(1),
+            system: field(2),
+            idle: field(3),
+            iowait: field(4),
+            irq: field(5),
+            softirq: field(6),
+            steal: field(7),
+            guest: field(8),
+            guest_nice: field(9),
+        })
+    }
+
+    fn total(&self) -> u64 {
+        self.user
+            + self.nice
+            + self.system
+            + self.idle
+            + self.iowait
+            + self.irq
+            + self.softirq
+            + self.steal
+            + self.guest
+            + self.guest_nice
+    }
+
+    fn idle_all(&self) -> u64 {
+        self.idle + self.iowait
+    }
+
+    /// Percentage of busy time between this sample and an earlier one
+    fn usage_since(&self, prev: &CpuTimes) -> f64 {
+        let total_delta = self.total().saturating_sub(prev.total());
+        if total_delta == 0 {
+            return 0.0;
+        }
+        let idle_delta = self.idle_all().saturating_sub(prev.idle_all());
+        let busy_delta = total_delta.saturating_sub(idle_delta);
+        100.0 * busy_delta as f64 / total_delta as f64
+    }
+}
+
+/// System metrics collector backed by the platform's `MetricsSource`
+#[derive(Debug, Clone, PartialEq)]
+pub struct SystemMetrics {
+    /// System uptime in seconds (floating point for precision)
+    uptime_seconds: f64,
+    /// System idle time in seconds
+    idle_time: f64,
+    /// Load averages (1min, 5min, 15min)
+    load_avg: (f64, f64, f64),
+    /// Number of unique logged-in users
+    user_count: usize,
+    /// System boot time as UNIX timestamp
+    boot_time: u64,
+    /// Most recent aggregate CPU time sample
+    #[cfg(target_os = "linux")]
+    cpu_times: Option<CpuTimes>,
+    /// Most recent per-core CPU time samples
+    #[cfg(target_os = "linux")]
+    per_cpu_times: Vec<CpuTimes>,
+    /// Previous aggregate CPU time sample, used to compute usage deltas
+    #[cfg(target_os = "linux")]
+    prev_cpu_times: Option<CpuTimes>,
+    /// Previous per-core CPU time samples
+    #[cfg(target_os = "linux")]
+    prev_per_cpu_times: Vec<CpuTimes>,
+    /// Overall CPU usage percentage since the previous sample
+    cpu_usage: Option<f64>,
+    /// Per-core CPU usage percentage since the previous sample
+    per_cpu_usage: Vec<f64>,
+    /// Total physical memory, in kB
+    mem_total_kb: u64,
+    /// Memory available for new allocations without swapping, in kB
+    mem_available_kb: u64,
+    /// Total swap space, in kB
+    swap_total_kb: u64,
+    /// Free swap space, in kB
+    swap_free_kb: u64,
+}
+
+impl Default for SystemMetrics {
+    fn default() -> Self {
+        Self {
+            uptime_seconds: 0.0,
+            idle_time: 0.0,
+            load_avg: (0.0, 0.0, 0.0),
+            user_count: 0,
+            boot_time: 0,
+            #[cfg(target_os = "linux")]
+            cpu_times: None,
+            #[cfg(target_os = "linux")]
+            per_cpu_times: Vec::new(),
+            #[cfg(target_os = "linux")]
+            prev_cpu_times: None,
+            #[cfg(target_os = "linux")]
+            prev_per_cpu_times: Vec::new(),
+            cpu_usage: None,
+            per_cpu_usage: Vec::new(),
+            mem_total_kb: 0,
+            mem_available_kb: 0,
+            swap_total_kb: 0,
+            swap_free_kb: 0,
+        }
+    }
+}
+
+impl SystemMetrics {
+    /// Creates a new SystemMetrics instance, reading from the platform's metrics source
+    pub fn new() -> io::Result<Self> {
+        let mut metrics = Self::default();
+
+        // Read uptime and idle time
+        metrics.read_uptime()?;
+
+        // Read load averages
+        metrics.read_loadavg()?;
+
+        // Read user count
+        metrics.read_users();
+
+        // Calculate boot time from uptime
+        metrics.calculate_boot_time()?;
+
+        // Take the first CPU time sample; usage is only known after a refresh
+        metrics.read_cpu_usage()?;
+
+        // Read memory and swap totals/availability
+        metrics.read_meminfo()?;
+
+        Ok(metrics)
+    }
+
+    /// Read uptime and idle time from the platform metrics source
+    fn read_uptime(&mut self) -> io::Result<()> {
+        let (uptime, idle) = PlatformSource.read_uptime()?;
+        self.uptime_seconds = uptime;
+        self.idle_time = idle;
+        Ok(())
+    }
+
+    /// Read load averages from the platform metrics source
+    fn read_loadavg(&mut self) -> io::Result<()> {
+        self.load_avg = PlatformSource.read_load_averages()?;
+        Ok(())
+    }
+
+    /// Count unique logged-in users from the platform metrics source
+    fn read_users(&mut self) {
+        self.user_count = PlatformSource.read_user_count();
+    }
+
+    /// Read cumulative CPU time counters from /proc/stat and derive usage
+    /// percentages against the previous sample
+    ///
+    /// CPU sampling is Linux-only for now; other platforms simply report no
+    /// usage data.
+    #[cfg(target_os = "linux")]
+    fn read_cpu_usage(&mut self) -> io::Result<()> {
+        let file = fs::File::open("/proc/stat")?;
+        let reader = BufReader::new(file);
+
+        let mut aggregate = None;
+        let mut per_cpu = Vec::new();
+
+        for line in reader.lines() {
+            let line = line?;
+            if !line.starts_with("cpu") {
+                break;
+            }
+
+            let mut fields = line.split_whitespace();
+            let label = fields.next().unwrap_or("");
+            let rest: Vec<&str> = fields.collect();
+            let times = match CpuTimes::parse(&rest) {
+                Some(times) => times,
+                None => continue,
+            };
+
+            if label == "cpu" {
+                aggregate = Some(times);
+            } else {
+                per_cpu.push(times);
+            }
+        }
+
+        self.prev_cpu_times = self.cpu_times.take();
+        self.prev_per_cpu_times = std::mem::take(&mut self.per_cpu_times);
+
+        self.cpu_usage = match (aggregate, self.prev_cpu_times) {
+            (Some(cur), Some(prev)) => Some(cur.usage_since(&prev)),
+            _ => None,
+        };
+
+        self.per_cpu_usage = if self.prev_per_cpu_times.len() == per_cpu.len() {
+            per_cpu
+                .iter()
+                .zip(self.prev_per_cpu_times.iter())
+                .map(|(cur, prev)| cur.usage_since(prev))
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        self.cpu_times = aggregate;
+        self.per_cpu_times = per_cpu;
+
+        Ok(())
+    }
+
+    /// CPU sampling is not yet implemented outside Linux; usage stays unknown
+    #[cfg(not(target_os = "linux"))]
+    fn read_cpu_usage(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+
+    /// Calculate boot time from the platform metrics source
+    fn calculate_boot_time(&mut self) -> io::Result<()> {
+        self.boot_time = PlatformSource.read_boot_time(self.uptime_seconds)?;
+        Ok(())
+    }
+
+    /// Read memory and swap totals from /proc/meminfo
+    ///
+    /// Memory reporting is Linux-only for now; other platforms simply
+    /// report zeroed totals.
+    #[cfg(target_os = "linux")]
+    fn read_meminfo(&mut self) -> io::Result<()> {
+        let content = fs::read_to_string("/proc/meminfo")?;
+        let field = |key: &str| -> u64 {
+            content
+                .lines()
+                .find(|line| line.starts_with(key))
+                .and_then(|line| line.split_whitespace().nth(1))
+                .and_then(|value| value.parse().ok())
+                .unwrap_or(0)
+        };
+
+        self.mem_total_kb = field("MemTotal:");
+        self.mem_available_kb = field("MemAvailable:");
+        self.swap_total_kb = field("SwapTotal:");
+        self.swap_free_kb = field("SwapFree:");
+
+        Ok(())
+    }
+
+    /// Memory reporting is not yet implemented outside Linux
+    #[cfg(not(target_os = "linux"))]
+    fn read_meminfo(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+
+    /// Get uptime in seconds with decimal precision
+    pub fn uptime_seconds(&self) -> f64 {
+        self.uptime_seconds
+    }
+
+    /// Get idle time in seconds
+    pub fn idle_time(&self) -> f64 {
+        self.idle_time
+    }
+
+    /// Get load averages as (1min, 5min, 15min)
+    pub fn load_averages(&self) -> (f64, f64, f64) {
+        self.load_avg
+    }
+
+    /// Get number of unique users
+    pub fn user_count(&self) -> usize {
+        self.user_count
+    }
+
+    /// Get system boot time as UNIX timestamp
+    pub fn boot_time(&self) -> u64 {
+        self.boot_time
+    }
+
+    /// Best-effort detection of whether the process is running in a container
+    ///
+    /// Checks for the marker files common container runtimes leave behind,
+    /// falling back to the `cgroup` membership of PID 1. This is inherently
+    /// heuristic; no single signal is authoritative across every runtime.
+    #[cfg(target_os = "linux")]
+    pub fn in_container(&self) -> bool {
+        if std::path::Path::new("/.dockerenv").exists()
+            || std::path::Path::new("/run/.containerenv").exists()
+        {
+            return true;
+        }
+
+        fs::read_to_string("/proc/1/cgroup")
+            .map(|cgroup| {
+                ["docker", "kubepods", "lxc", "containerd"]
+                    .iter()
+                    .any(|marker| cgroup.contains(marker))
+            })
+            .unwrap_or(false)
+    }
+
+    /// Container detection relies on Linux-specific marker files and cgroups
+    #[cfg(not(target_os = "linux"))]
+    pub fn in_container(&self) -> bool {
+        false
+    }
+
+    /// Get overall CPU usage percentage since the previous sample
+    ///
+    /// Returns `None` until a second sample has been taken (e.g. after the
+    /// first `refresh()`).
+    pub fn cpu_usage(&self) -> Option<f64> {
+        self.cpu_usage
+    }
+
+    /// Get per-core CPU usage percentages since the previous sample
+    ///
+    /// Empty until a second sample has been taken.
+    pub fn per_cpu_usage(&self) -> &[f64] {
+        &self.per_cpu_usage
+    }
+
+    /// Fraction of physical memory currently in use, from `0.0` to `1.0`
+    pub fn memory_used_ratio(&self) -> f64 {
+        if self.mem_total_kb == 0 {
+            return 0.0;
+        }
+        let used = self.mem_total_kb.saturating_sub(self.mem_available_kb);
+        (used as f64 / self.mem_total_kb as f64).clamp(0.0, 1.0)
+    }
+
+    /// Fraction of swap space currently in use, from `0.0` to `1.0`
+    pub fn swap_used_ratio(&self) -> f64 {
+        if self.swap_total_kb == 0 {
+            return 0.0;
+        }
+        let used = self.swap_total_kb.saturating_sub(self.swap_free_kb);
+        (used as f64 / self.swap_total_kb as f64).clamp(0.0, 1.0)
+    }
+
+    /// Number of CPU cores sampled from /proc/stat's per-core lines
+    ///
+    /// Always at least 1.
+    #[cfg(target_os = "linux")]
+    pub fn num_cpus(&self) -> usize {
+        self.per_cpu_times.len().max(1)
+    }
+
+    /// CPU core counting relies on Linux's per-core /proc/stat lines
+    #[cfg(not(target_os = "linux"))]
+    pub fn num_cpus(&self) -> usize {
+        1
+    }
+
+    /// Percentage of total CPU time spent idle since boot, aggregated across
+    /// all cores and clamped to `0.0..=100.0`
+    ///
+    /// Returns `None` on platforms without a real idle-time counter; only
+    /// Linux's /proc/uptime exposes one, so macOS and OpenBSD backends
+    /// always report zero idle time, which would otherwise read as a
+    /// permanently, falsely "critically busy" system.
+    #[cfg(target_os = "linux")]
+    pub fn idle_percentage(&self) -> Option<f64> {
+        let total_seconds = self.uptime_seconds * self.num_cpus() as f64;
+        if total_seconds <= 0.0 {
+            return Some(0.0);
+        }
+        Some((self.idle_time / total_seconds * 100.0).clamp(0.0, 100.0))
+    }
+
+    /// Idle-time tracking is not yet implemented outside Linux
+    #[cfg(not(target_os = "linux"))]
+    pub fn idle_percentage(&self) -> Option<f64> {
+        None
+    }
+
+    /// Refresh all metrics
+    pub fn refresh(&mut self) -> io::Result<()> {
+        self.read_uptime()?;
+        self.read_loadavg()?;
+        self.read_users();
+        self.calculate_boot_time()?;
+        self.read_cpu_usage()?;
+        self.read_meminfo()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn usage_since_computes_busy_fraction_from_counter_deltas() {
+        let prev = CpuTimes {
+            user: 100,
+            idle: 900,
+            ..Default::default()
+        };
+        let cur = CpuTimes {
+            user: 150,
+            idle: 950,
+            ..Default::default()
+        };
+
+        // 50 of the 100 elapsed ticks were busy.
+        assert!((cur.usage_since(&prev) - 50.0).abs() < f64::EPSILON);
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn usage_since_returns_zero_when_total_delta_is_zero() {
+        let sample = CpuTimes {
+            user: 100,
+            idle: 900,
+            ..Default::default()
+        };
+
+        assert_eq!(sample.usage_since(&sample), 0.0);
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn usage_since_handles_a_counter_reset_without_underflowing() {
+        // Counters restart from zero after a reboot, so `prev` can end up
+        // larger than `cur`; saturating_sub must keep this from underflowing
+        // into a huge bogus delta.
+        let prev = CpuTimes {
+            user: 1_000,
+            idle: 9_000,
+            ..Default::default()
+        };
+        let cur = CpuTimes {
+            user: 10,
+            idle: 90,
+            ..Default::default()
+        };
+
+        assert_eq!(cur.usage_since(&prev), 0.0);
+    }
+
+    #[test]
+    fn memory_used_ratio_is_zero_when_total_is_zero() {
+        let metrics = SystemMetrics {
+            mem_total_kb: 0,
+            mem_available_kb: 0,
+            ..Default::default()
+        };
+
+        assert_eq!(metrics.memory_used_ratio(), 0.0);
+    }
+
+    #[test]
+    fn memory_used_ratio_divides_used_by_total() {
+        let metrics = SystemMetrics {
+            mem_total_kb: 1000,
+            mem_available_kb: 250,
+            ..Default::default()
+        };
+
+        assert!((metrics.memory_used_ratio() - 0.75).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn swap_used_ratio_is_zero_when_total_is_zero() {
+        let metrics = SystemMetrics {
+            swap_total_kb: 0,
+            swap_free_kb: 0,
+            ..Default::default()
+        };
+
+        assert_eq!(metrics.swap_used_ratio(), 0.0);
+    }
+
+    #[test]
+    fn swap_used_ratio_divides_used_by_total() {
+        let metrics = SystemMetrics {
+            swap_total_kb: 2000,
+            swap_free_kb: 500,
+            ..Default::default()
+        };
+
+        assert!((metrics.swap_used_ratio() - 0.75).abs() < f64::EPSILON);
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn idle_percentage_is_zero_when_uptime_is_zero() {
+        let metrics = SystemMetrics {
+            uptime_seconds: 0.0,
+            idle_time: 0.0,
+            ..Default::default()
+        };
+
+        assert_eq!(metrics.idle_percentage(), Some(0.0));
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn idle_percentage_clamps_to_one_hundred() {
+        // idle_time exceeding uptime * num_cpus can't happen in practice, but
+        // the clamp should still keep the result within range.
+        let metrics = SystemMetrics {
+            uptime_seconds: 10.0,
+            idle_time: 1000.0,
+            ..Default::default()
+        };
+
+        assert_eq!(metrics.idle_percentage(), Some(100.0));
+    }
+}
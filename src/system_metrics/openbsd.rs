@@ -0,0 +1,82 @@
+//! OpenBSD metrics backend using sysctl boot time, `getloadavg`, and the classic utmp format
+//!
+//! OpenBSD never adopted utmpx; login accounting still uses the original
+//! utmp record layout at `/var/run/utmp`, so this backend reads it with the
+//! `utmp-classic` crate rather than `utmpx`.
+
+use super::source::MetricsSource;
+use std::collections::HashSet;
+use std::io;
+use std::mem;
+use std::ptr;
+use std::time::{SystemTime, UNIX_EPOCH};
+use utmp_classic::{parse_from_path, UtmpEntry};
+
+/// Reads uptime, load, and user metrics for OpenBSD
+#[derive(Debug, Default, Clone, Copy)]
+pub struct OpenBsdSource;
+
+impl MetricsSource for OpenBsdSource {
+    /// OpenBSD has no idle-time counter equivalent to /proc/uptime's second field
+    fn read_uptime(&self) -> io::Result<(f64, f64)> {
+        let boot_time = self.read_boot_time(0.0)?;
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+
+        Ok((now.saturating_sub(boot_time) as f64, 0.0))
+    }
+
+    fn read_load_averages(&self) -> io::Result<(f64, f64, f64)> {
+        let mut loads = [0.0f64; 3];
+        let filled = unsafe { libc::getloadavg(loads.as_mut_ptr(), 3) };
+
+        if filled != 3 {
+            return Err(io::Error::new(io::ErrorKind::Other, "getloadavg failed"));
+        }
+
+        Ok((loads[0], loads[1], loads[2]))
+    }
+
+    /// Count unique users from the classic /var/run/utmp database
+    fn read_user_count(&self) -> usize {
+        let mut unique_users: HashSet<String> = HashSet::new();
+
+        if let Ok(entries) = parse_from_path("/var/run/utmp") {
+            for entry in entries {
+                if let UtmpEntry::UTMP { user, .. } = entry {
+                    if !user.is_empty() {
+                        unique_users.insert(user);
+                    }
+                }
+            }
+        }
+
+        unique_users.len()
+    }
+
+    /// Read `kern.boottime` via the `CTL_KERN`/`KERN_BOOTTIME` sysctl MIB
+    fn read_boot_time(&self, _uptime_seconds: f64) -> io::Result<u64> {
+        let mut boottime: libc::timeval = unsafe { mem::zeroed() };
+        let mut size = mem::size_of::<libc::timeval>();
+        let mib = [libc::CTL_KERN, libc::KERN_BOOTTIME];
+
+        let ret = unsafe {
+            libc::sysctl(
+                mib.as_ptr() as *mut libc::c_int,
+                mib.len() as libc::c_uint,
+                &mut boottime as *mut _ as *mut libc::c_void,
+                &mut size,
+                ptr::null_mut(),
+                0,
+            )
+        };
+
+        if ret != 0 {
+            return Err(io::Error::last_os_error());
+        }
+
+        Ok(boottime.tv_sec as u64)
+    }
+}
@@ -0,0 +1,23 @@
+//! Platform abstraction for collecting raw system metrics
+//!
+//! `SystemMetrics` delegates everything that differs by operating system to a
+//! `MetricsSource` implementation, chosen at compile time in `mod.rs` via
+//! `cfg(target_os = ...)`. This keeps the platform-specific reading code
+//! (proc filesystem, sysctl, utmp formats, ...) isolated to one module per OS.
+
+use std::io;
+
+/// A source of low-level system metrics for a particular platform
+pub trait MetricsSource {
+    /// Returns uptime and idle time, both in seconds
+    fn read_uptime(&self) -> io::Result<(f64, f64)>;
+
+    /// Returns the 1, 5, and 15 minute load averages
+    fn read_load_averages(&self) -> io::Result<(f64, f64, f64)>;
+
+    /// Counts unique logged-in users
+    fn read_user_count(&self) -> usize;
+
+    /// Returns system boot time as a UNIX timestamp
+    fn read_boot_time(&self, uptime_seconds: f64) -> io::Result<u64>;
+}